@@ -13,6 +13,7 @@ use futures::StreamExt;
 use std::collections::{BTreeMap, HashMap};
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
 use sui_config::node::RunWithRange;
 use sui_test_transaction_builder::PublishData;
 use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber, SuiAddress};
@@ -22,13 +23,20 @@ use sui_types::mock_checkpoint_builder::ValidatorKeypairProvider;
 use sui_types::transaction::{
     CertifiedTransaction, SignedTransaction, Transaction, VerifiedTransaction,
 };
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
 use tracing::info;
 
 pub struct BenchmarkContext {
     validator: SingleValidator,
     user_accounts: BTreeMap<SuiAddress, Account>,
+    // Accounts of the warm-up transactions, kept apart so that warm-up never touches the
+    // objects of the benchmarked transactions.
+    warmup_accounts: BTreeMap<SuiAddress, Account>,
     admin_account: Account,
     benchmark_component: Component,
+    duration: Option<Duration>,
+    abort_on_failure: bool,
 }
 
 impl BenchmarkContext {
@@ -38,7 +46,7 @@ impl BenchmarkContext {
         print_sample_tx: bool,
     ) -> Self {
         // Reserve 1 account for package publishing.
-        let mut num_accounts = workload.num_accounts() + 1;
+        let mut num_accounts = workload.num_accounts() + workload.warmup_tx_count + 1;
         if print_sample_tx {
             // Reserver another one to generate a sample transaction.
             num_accounts += 1;
@@ -54,15 +62,26 @@ impl BenchmarkContext {
             batch_create_account_and_gas(num_accounts, gas_object_num_per_account).await;
         assert_eq!(genesis_gas_objects.len() as u64, total);
         let (_, admin_account) = user_accounts.pop_last().unwrap();
+        let warmup_accounts = (0..workload.warmup_tx_count)
+            .map(|_| user_accounts.pop_last().unwrap())
+            .collect();
 
         info!("Initializing validator");
-        let validator = SingleValidator::new(&genesis_gas_objects, benchmark_component).await;
+        let validator = SingleValidator::new(
+            &genesis_gas_objects,
+            benchmark_component,
+            workload.reference_gas_price,
+        )
+        .await;
 
         Self {
             validator,
             user_accounts,
+            warmup_accounts,
             admin_account,
             benchmark_component,
+            duration: workload.duration,
+            abort_on_failure: workload.abort_on_failure,
         }
     }
 
@@ -70,6 +89,16 @@ impl BenchmarkContext {
         self.validator.clone()
     }
 
+    /// Swap the user accounts with the warm-up accounts, so that setup and transaction
+    /// generation run for the warm-up accounts until swapped back.
+    pub(crate) fn swap_warmup_accounts(&mut self) {
+        std::mem::swap(&mut self.user_accounts, &mut self.warmup_accounts);
+    }
+
+    pub(crate) fn reference_gas_price(&self) -> u64 {
+        self.validator.get_epoch_store().reference_gas_price()
+    }
+
     pub(crate) async fn publish_package(&mut self, publish_data: PublishData) -> ObjectRef {
         let mut gas_objects = self.admin_account.gas_objects.deref().clone();
         let (package, updated_gas) = self
@@ -104,6 +133,7 @@ impl BenchmarkContext {
             .generate_transactions(Arc::new(RootObjectCreateTxGenerator::new(
                 move_package,
                 num_dynamic_fields,
+                self.reference_gas_price(),
             )))
             .await;
         let results = self
@@ -153,7 +183,8 @@ impl BenchmarkContext {
         assert!(num_shared_objects <= self.user_accounts.len());
 
         info!("Preparing shared objects");
-        let generator = SharedObjectCreateTxGenerator::new(move_package);
+        let generator =
+            SharedObjectCreateTxGenerator::new(move_package, self.reference_gas_price());
         let shared_object_create_transactions: Vec<_> = self
             .user_accounts
             .values()
@@ -219,6 +250,17 @@ impl BenchmarkContext {
         results.into_iter().map(|r| r.unwrap()).collect()
     }
 
+    /// Generate one warm-up transaction for each warm-up account.
+    pub(crate) async fn generate_warmup_transactions(
+        &mut self,
+        tx_generator: Arc<dyn TxGenerator>,
+    ) -> Vec<Transaction> {
+        self.swap_warmup_accounts();
+        let transactions = self.generate_transactions(tx_generator).await;
+        self.swap_warmup_accounts();
+        transactions
+    }
+
     pub(crate) async fn certify_transactions(
         &self,
         transactions: Vec<Transaction>,
@@ -259,8 +301,9 @@ impl BenchmarkContext {
     pub(crate) async fn benchmark_transaction_execution(
         &self,
         transactions: Vec<CertifiedTransaction>,
+        warmup_transactions: Vec<CertifiedTransaction>,
         print_sample_tx: bool,
-    ) {
+    ) -> Vec<TransactionEffects> {
         if print_sample_tx {
             // We must use remove(0) in case there are shared objects and the transactions
             // must be executed in order.
@@ -268,49 +311,41 @@ impl BenchmarkContext {
                 .await;
         }
 
+        if !warmup_transactions.is_empty() {
+            info!("Warming up with {} transactions", warmup_transactions.len());
+            self.execute_certificates(warmup_transactions, None).await;
+        }
+
         let tx_count = transactions.len();
-        let start_time = std::time::Instant::now();
+        let start_time = Instant::now();
         info!(
             "Started executing {} transactions. You can now attach a profiler",
             transactions.len()
         );
 
-        let has_shared_object = transactions.iter().any(|tx| tx.contains_shared_object());
-        if has_shared_object {
-            // With shared objects, we must execute each transaction in order.
-            for transaction in transactions {
-                self.validator
-                    .execute_certificate(transaction, self.benchmark_component)
-                    .await;
-            }
-        } else {
-            let tasks: FuturesUnordered<_> = transactions
-                .into_iter()
-                .map(|tx| {
-                    let validator = self.validator();
-                    let component = self.benchmark_component;
-                    tokio::spawn(async move { validator.execute_certificate(tx, component).await })
-                })
-                .collect();
-            let results: Vec<_> = tasks.collect().await;
-            results.into_iter().for_each(|r| {
-                r.unwrap();
-            });
-        }
+        let effects = self
+            .execute_certificates(transactions, self.deadline(start_time))
+            .await;
 
-        let elapsed = start_time.elapsed().as_millis() as f64 / 1000f64;
+        let elapsed = start_time.elapsed();
+        Self::log_partial_execution(effects.len(), tx_count);
+        Self::log_failed_transactions(&effects);
         info!(
             "Execution finished in {}s, TPS={}",
-            elapsed,
-            tx_count as f64 / elapsed
+            elapsed.as_millis() as f64 / 1000f64,
+            Self::tps(effects.len(), elapsed)
         );
+        Self::log_effects_size(&effects);
+        Self::log_object_changes(&effects);
+        effects
     }
 
     pub(crate) async fn benchmark_transaction_execution_in_memory(
         &self,
         transactions: Vec<CertifiedTransaction>,
+        warmup_transactions: Vec<CertifiedTransaction>,
         print_sample_tx: bool,
-    ) {
+    ) -> Vec<TransactionEffects> {
         if print_sample_tx {
             self.execute_sample_transaction(transactions[0].clone())
                 .await;
@@ -318,21 +353,88 @@ impl BenchmarkContext {
 
         let tx_count = transactions.len();
         let in_memory_store = self.validator.create_in_memory_store();
-        let start_time = std::time::Instant::now();
+        if !warmup_transactions.is_empty() {
+            info!("Warming up with {} transactions", warmup_transactions.len());
+            self.execute_transactions_in_memory(in_memory_store.clone(), warmup_transactions, None)
+                .await;
+            // Only report the reads and lock waits of the benchmarked transactions.
+            in_memory_store.reset_stats();
+        }
+        let start_time = Instant::now();
         info!(
             "Started executing {} transactions. You can now attach a profiler",
             transactions.len()
         );
 
-        self.execute_transactions_in_memory(in_memory_store.clone(), transactions)
+        let effects = self
+            .execute_transactions_in_memory(
+                in_memory_store.clone(),
+                transactions,
+                self.deadline(start_time),
+            )
             .await;
 
-        let elapsed = start_time.elapsed().as_millis() as f64 / 1000f64;
+        let elapsed = start_time.elapsed();
+        Self::log_partial_execution(effects.len(), tx_count);
+        Self::log_failed_transactions(&effects);
         info!(
             "Execution finished in {}s, TPS={}, number of DB object reads per transaction: {}",
-            elapsed,
-            tx_count as f64 / elapsed,
-            in_memory_store.get_num_object_reads() as f64 / tx_count as f64
+            elapsed.as_millis() as f64 / 1000f64,
+            Self::tps(effects.len(), elapsed),
+            in_memory_store.get_num_object_reads() as f64 / effects.len().max(1) as f64
+        );
+        // Tells whether the global lock on the in-memory store, rather than execution, is the
+        // bottleneck.
+        let executed = effects.len().max(1) as f64;
+        info!(
+            "Time spent waiting for the in-memory store lock per transaction: read={}us, write={}us",
+            in_memory_store.get_read_lock_wait_ns() as f64 / 1000f64 / executed,
+            in_memory_store.get_write_lock_wait_ns() as f64 / 1000f64 / executed
+        );
+        Self::log_effects_size(&effects);
+        Self::log_object_changes(&effects);
+        effects
+    }
+
+    /// Transactions per second over `elapsed`. Returns 0 rather than NaN or infinity when no time
+    /// has elapsed, e.g. when a zero duration limit stops the run before anything executes.
+    fn tps(tx_count: usize, elapsed: Duration) -> f64 {
+        let elapsed = elapsed.as_secs_f64();
+        if elapsed > 0.0 {
+            tx_count as f64 / elapsed
+        } else {
+            0.0
+        }
+    }
+
+    /// Log the serialized size of the effects of the benchmarked transactions, which tells
+    /// storage-heavy workloads apart from compute-heavy ones. This is computed after the
+    /// measured execution so that it does not affect the TPS. `bcs::serialized_size` only
+    /// counts bytes and does not allocate a serialization buffer.
+    fn log_effects_size(effects: &[TransactionEffects]) {
+        if effects.is_empty() {
+            return;
+        }
+        let sizes: Vec<_> = effects
+            .iter()
+            .map(|e| bcs::serialized_size(e).unwrap())
+            .collect();
+        let total: usize = sizes.iter().sum();
+        info!(
+            "Effects size: mean={} bytes, max={} bytes, total={} bytes",
+            total as f64 / sizes.len() as f64,
+            sizes.iter().max().unwrap(),
+            total
+        );
+    }
+
+    /// Log how many objects the benchmarked transactions created, mutated, deleted and wrapped,
+    /// which tells whether a workload grows the object set or churns through it.
+    fn log_object_changes(effects: &[TransactionEffects]) {
+        let counts = ObjectChangeCounts::new(effects);
+        info!(
+            "Object changes: created={}, mutated={}, deleted={}, wrapped={}",
+            counts.created, counts.mutated, counts.deleted, counts.wrapped
         );
     }
 
@@ -356,6 +458,7 @@ impl BenchmarkContext {
     pub(crate) async fn benchmark_transaction_signing(
         &self,
         transactions: Vec<Transaction>,
+        warmup_transactions: Vec<Transaction>,
         print_sample_tx: bool,
     ) {
         if print_sample_tx {
@@ -363,14 +466,19 @@ impl BenchmarkContext {
             info!("Sample transaction: {:?}", sample_transaction.data());
         }
 
+        if !warmup_transactions.is_empty() {
+            info!("Warming up with {} transactions", warmup_transactions.len());
+            self.validator_sign_transactions(warmup_transactions).await;
+        }
+
         let tx_count = transactions.len();
         let start_time = std::time::Instant::now();
         self.validator_sign_transactions(transactions).await;
-        let elapsed = start_time.elapsed().as_millis() as f64 / 1000f64;
+        let elapsed = start_time.elapsed();
         info!(
             "Transaction signing finished in {}s, TPS={}.",
-            elapsed,
-            tx_count as f64 / elapsed,
+            elapsed.as_millis() as f64 / 1000f64,
+            Self::tps(tx_count, elapsed),
         );
     }
 
@@ -378,24 +486,25 @@ impl BenchmarkContext {
         &self,
         transactions: Vec<CertifiedTransaction>,
         checkpoint_size: usize,
-    ) {
+    ) -> Vec<TransactionEffects> {
         self.execute_sample_transaction(transactions[0].clone())
             .await;
 
         info!("Executing all transactions to generate effects");
         let tx_count = transactions.len();
         let in_memory_store = self.validator.create_in_memory_store();
-        let effects: BTreeMap<_, _> = self
-            .execute_transactions_in_memory(in_memory_store.clone(), transactions.clone())
-            .await
-            .into_iter()
-            .map(|e| (*e.transaction_digest(), e))
+        let effects = self
+            .execute_transactions_in_memory(in_memory_store.clone(), transactions.clone(), None)
+            .await;
+        let effects_by_digest: BTreeMap<_, _> = effects
+            .iter()
+            .map(|e| (*e.transaction_digest(), e.clone()))
             .collect();
 
         info!("Building checkpoints");
         let validator = self.validator();
         let checkpoints = validator
-            .build_checkpoints(transactions, effects, checkpoint_size)
+            .build_checkpoints(transactions, effects_by_digest, checkpoint_size)
             .await;
         info!("Built {} checkpoints", checkpoints.len());
         let last_checkpoint_seq = *checkpoints.last().unwrap().0.sequence_number();
@@ -428,12 +537,13 @@ impl BenchmarkContext {
                 Some(RunWithRange::Checkpoint(last_checkpoint_seq)),
             )
             .await;
-        let elapsed = start_time.elapsed().as_millis() as f64 / 1000f64;
+        let elapsed = start_time.elapsed();
         info!(
             "Checkpoint execution finished in {}s, TPS={}.",
-            elapsed,
-            tx_count as f64 / elapsed,
+            elapsed.as_millis() as f64 / 1000f64,
+            Self::tps(tx_count, elapsed),
         );
+        effects
     }
 
     async fn execute_raw_transactions(
@@ -451,21 +561,64 @@ impl BenchmarkContext {
         results.into_iter().map(|r| r.unwrap()).collect()
     }
 
+    /// Execute certificates through the benchmarked component. If a deadline is given, execution
+    /// stops once it passes, and only the effects of the transactions executed so far are returned.
+    async fn execute_certificates(
+        &self,
+        transactions: Vec<CertifiedTransaction>,
+        deadline: Option<Instant>,
+    ) -> Vec<TransactionEffects> {
+        let has_shared_object = transactions.iter().any(|tx| tx.contains_shared_object());
+        if has_shared_object {
+            // With shared objects, we must execute each transaction in order.
+            let mut effects = Vec::new();
+            for transaction in transactions {
+                if Self::deadline_passed(deadline) {
+                    break;
+                }
+                let tx_effects = self
+                    .validator
+                    .execute_certificate(transaction, self.benchmark_component)
+                    .await;
+                self.check_effects(&tx_effects);
+                effects.push(tx_effects);
+            }
+            effects
+        } else {
+            let tasks: FuturesUnordered<_> = transactions
+                .into_iter()
+                .map(|tx| {
+                    let validator = self.validator();
+                    let component = self.benchmark_component;
+                    tokio::spawn(async move { validator.execute_certificate(tx, component).await })
+                })
+                .collect();
+            self.collect_effects(tasks, deadline).await
+        }
+    }
+
+    /// Execute transactions against the in-memory store. If a deadline is given, execution stops
+    /// once it passes, and only the effects of the transactions executed so far are returned.
     async fn execute_transactions_in_memory(
         &self,
         store: InMemoryObjectStore,
         transactions: Vec<CertifiedTransaction>,
+        deadline: Option<Instant>,
     ) -> Vec<TransactionEffects> {
         let has_shared_object = transactions.iter().any(|tx| tx.contains_shared_object());
         if has_shared_object {
             // With shared objects, we must execute each transaction in order.
             let mut effects = Vec::new();
             for transaction in transactions {
-                effects.push(
-                    self.validator
-                        .execute_transaction_in_memory(store.clone(), transaction)
-                        .await,
-                );
+                if Self::deadline_passed(deadline) {
+                    break;
+                }
+                let tx_effects = self
+                    .validator
+                    .execute_transaction_in_memory(store.clone(), transaction)
+                    .await;
+                self.check_effects(&tx_effects);
+                effects.push(tx_effects);
             }
             effects
         } else {
@@ -479,8 +632,82 @@ impl BenchmarkContext {
                     )
                 })
                 .collect();
-            let results: Vec<_> = tasks.collect().await;
-            results.into_iter().map(|r| r.unwrap()).collect()
+            self.collect_effects(tasks, deadline).await
+        }
+    }
+
+    fn deadline(&self, start_time: Instant) -> Option<Instant> {
+        self.duration.map(|duration| start_time + duration)
+    }
+
+    fn deadline_passed(deadline: Option<Instant>) -> bool {
+        deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Wait for spawned executions to finish, in completion order. If the deadline passes first,
+    /// the outstanding executions are aborted and only the effects collected so far are returned.
+    /// Each effects is passed to `check_effects`, and outstanding executions are aborted before
+    /// the benchmark aborts on a failed transaction.
+    async fn collect_effects(
+        &self,
+        mut tasks: FuturesUnordered<JoinHandle<TransactionEffects>>,
+        deadline: Option<Instant>,
+    ) -> Vec<TransactionEffects> {
+        let mut results = Vec::with_capacity(tasks.len());
+        loop {
+            // `None` once all tasks have finished, or once the deadline has passed.
+            let next = match deadline {
+                Some(deadline) if Instant::now() >= deadline => None,
+                Some(deadline) => tokio::time::timeout_at(deadline, tasks.next())
+                    .await
+                    .ok()
+                    .flatten(),
+                None => tasks.next().await,
+            };
+            let Some(effects) = next else {
+                break;
+            };
+            let effects = effects.unwrap();
+            if self.is_unexpected_failure(&effects) {
+                tasks.iter().for_each(|task| task.abort());
+            }
+            self.check_effects(&effects);
+            results.push(effects);
+        }
+        // Abort whatever is still running after the deadline.
+        tasks.iter().for_each(|task| task.abort());
+        results
+    }
+
+    fn is_unexpected_failure(&self, effects: &TransactionEffects) -> bool {
+        self.abort_on_failure && !effects.status().is_ok()
+    }
+
+    /// Abort the benchmark with the failed transaction's details if it is not expected to fail.
+    fn check_effects(&self, effects: &TransactionEffects) {
+        if self.is_unexpected_failure(effects) {
+            panic!(
+                "Transaction {} failed with status {:?}, aborting benchmark. Effects: {:?}",
+                effects.transaction_digest(),
+                effects.status(),
+                effects
+            );
+        }
+    }
+
+    fn log_failed_transactions(effects: &[TransactionEffects]) {
+        let failed = effects.iter().filter(|e| !e.status().is_ok()).count();
+        if failed > 0 {
+            info!("{} of {} transactions failed", failed, effects.len());
+        }
+    }
+
+    fn log_partial_execution(executed: usize, tx_count: usize) {
+        if executed < tx_count {
+            info!(
+                "Benchmark duration elapsed after executing {} of {} transactions",
+                executed, tx_count
+            );
         }
     }
 
@@ -520,3 +747,410 @@ impl BenchmarkContext {
         results.into_iter().map(|r| r.unwrap()).collect()
     }
 }
+
+/// Number of objects created, mutated (including gas objects), deleted and wrapped by a set of
+/// transactions.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ObjectChangeCounts {
+    created: usize,
+    mutated: usize,
+    deleted: usize,
+    wrapped: usize,
+}
+
+impl ObjectChangeCounts {
+    fn new(effects: &[TransactionEffects]) -> Self {
+        let mut counts = Self::default();
+        for effects in effects {
+            counts.created += effects.created().len();
+            counts.mutated += effects.mutated().len();
+            counts.deleted += effects.deleted().len();
+            counts.wrapped += effects.wrapped().len();
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::WorkloadKind;
+    use crate::workload::{max_reference_gas_price, min_gas_budget};
+    use futures::FutureExt;
+    use std::collections::HashSet;
+    use std::panic::AssertUnwindSafe;
+    use std::path::PathBuf;
+    use sui_macros::sim_test;
+    use sui_test_transaction_builder::TestTransactionBuilder;
+    use sui_types::error::UserInputError;
+    use sui_types::execution_status::{ExecutionFailureStatus, ExecutionStatus};
+    use sui_types::transaction::{
+        CallArg, InputObjectKind, TransactionDataAPI, DEFAULT_VALIDATOR_GAS_PRICE,
+    };
+
+    fn ptb_workload(num_shared_objects: usize) -> Workload {
+        Workload::new(
+            10,
+            WorkloadKind::PTB {
+                num_transfers: 1,
+                use_native_transfer: true,
+                num_dynamic_fields: 0,
+                computation: 0,
+                num_shared_objects,
+                num_mints: 0,
+                nft_size: 32,
+                use_batch_mint: false,
+                gas_budget: None,
+            },
+        )
+    }
+
+    async fn certified_transactions(
+        ctx: &mut BenchmarkContext,
+        workload: &Workload,
+    ) -> Vec<CertifiedTransaction> {
+        let (tx_generator, _) = workload.create_tx_generators(ctx).await;
+        let transactions = ctx.generate_transactions(tx_generator).await;
+        let transactions = ctx.certify_transactions(transactions, true).await;
+        ctx.validator()
+            .assigned_shared_object_versions(&transactions)
+            .await;
+        transactions
+    }
+
+    #[sim_test]
+    async fn test_execution_stops_at_deadline() {
+        // Cover both the sequential (shared objects) and the parallel execution paths.
+        for num_shared_objects in [0, 1] {
+            let workload = ptb_workload(num_shared_objects);
+            let mut ctx = BenchmarkContext::new(workload.clone(), Component::Baseline, false).await;
+            let transactions = certified_transactions(&mut ctx, &workload).await;
+            let tx_count = transactions.len();
+
+            // A deadline that has already passed stops execution before any transaction.
+            // Executing in memory does not write to the store, so the same transactions
+            // can be executed again afterwards.
+            let effects = ctx
+                .execute_transactions_in_memory(
+                    ctx.validator.create_in_memory_store(),
+                    transactions.clone(),
+                    Some(Instant::now()),
+                )
+                .await;
+            assert!(effects.len() < tx_count);
+
+            // Without a deadline, every transaction is executed.
+            let effects = ctx
+                .execute_transactions_in_memory(
+                    ctx.validator.create_in_memory_store(),
+                    transactions.clone(),
+                    None,
+                )
+                .await;
+            assert_eq!(effects.len(), tx_count);
+
+            let effects = ctx
+                .execute_certificates(transactions, Some(Instant::now()))
+                .await;
+            assert!(effects.len() < tx_count);
+        }
+    }
+
+    #[sim_test]
+    async fn test_generated_transactions_use_reference_gas_price() {
+        let reference_gas_price = max_reference_gas_price();
+        let workload = ptb_workload(0).with_reference_gas_price(reference_gas_price);
+        let mut ctx = BenchmarkContext::new(workload.clone(), Component::Baseline, false).await;
+        assert_eq!(ctx.reference_gas_price(), reference_gas_price);
+
+        let (tx_generator, _) = workload.create_tx_generators(&mut ctx).await;
+        let transactions = ctx.generate_transactions(tx_generator).await;
+        assert!(!transactions.is_empty());
+        for tx in &transactions {
+            assert_eq!(tx.transaction_data().gas_price(), reference_gas_price);
+        }
+    }
+
+    #[sim_test]
+    async fn test_generated_transactions_use_gas_budget() {
+        let gas_budget = 2_000_000_000;
+        let mut manifest_file = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        manifest_file.extend([
+            "tests",
+            "data",
+            "package_publish_from_source",
+            "manifest.json",
+        ]);
+        let workload_kinds = [
+            WorkloadKind::PTB {
+                num_transfers: 1,
+                use_native_transfer: false,
+                num_dynamic_fields: 1,
+                computation: 1,
+                num_shared_objects: 1,
+                num_mints: 1,
+                nft_size: 32,
+                use_batch_mint: false,
+                gas_budget: Some(gas_budget),
+            },
+            WorkloadKind::Publish {
+                manifest_file,
+                gas_budget: Some(gas_budget),
+            },
+        ];
+        for workload_kind in workload_kinds {
+            let workload = Workload::new(10, workload_kind);
+            let mut ctx = BenchmarkContext::new(workload.clone(), Component::Baseline, false).await;
+            let (tx_generator, _) = workload.create_tx_generators(&mut ctx).await;
+            let transactions = ctx.generate_transactions(tx_generator).await;
+            assert_eq!(transactions.len(), 10);
+            for tx in &transactions {
+                assert_eq!(tx.transaction_data().gas_budget(), gas_budget);
+            }
+        }
+    }
+
+    #[sim_test]
+    async fn test_transaction_under_reference_gas_price_is_rejected() {
+        let reference_gas_price = 2500;
+        let ctx = BenchmarkContext::new(
+            ptb_workload(0).with_reference_gas_price(reference_gas_price),
+            Component::Baseline,
+            false,
+        )
+        .await;
+        let account = ctx.user_accounts.values().next().unwrap().clone();
+        let validator = ctx.validator();
+        let build_tx = |gas_price| {
+            VerifiedTransaction::new_unchecked(
+                TestTransactionBuilder::new(account.sender, account.gas_objects[0], gas_price)
+                    .transfer_sui(None, account.sender)
+                    .build_and_sign(account.keypair.as_ref()),
+            )
+        };
+
+        let error = validator
+            .get_validator()
+            .handle_transaction(
+                validator.get_epoch_store(),
+                build_tx(reference_gas_price - 1),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            UserInputError::try_from(error).unwrap(),
+            UserInputError::GasPriceUnderRGP {
+                gas_price: reference_gas_price - 1,
+                reference_gas_price,
+            }
+        );
+
+        // The same transaction at the reference gas price is accepted.
+        validator
+            .get_validator()
+            .handle_transaction(validator.get_epoch_store(), build_tx(reference_gas_price))
+            .await
+            .unwrap();
+    }
+
+    /// A transaction that passes input checks but runs out of gas during execution: a heavy
+    /// computation with the minimum gas budget.
+    fn out_of_gas_transaction(
+        ctx: &BenchmarkContext,
+        account: &Account,
+        move_package: ObjectID,
+    ) -> Transaction {
+        TestTransactionBuilder::new(
+            account.sender,
+            account.gas_objects[0],
+            ctx.reference_gas_price(),
+        )
+        .move_call(
+            move_package,
+            "benchmark",
+            "run_computation",
+            vec![CallArg::Pure(bcs::to_bytes(&1_000_000u64).unwrap())],
+        )
+        .with_gas_budget(min_gas_budget(ctx.reference_gas_price()))
+        .build_and_sign(account.keypair.as_ref())
+    }
+
+    #[sim_test]
+    async fn test_abort_on_failure() {
+        for abort_on_failure in [true, false] {
+            let workload = ptb_workload(0).with_abort_on_failure(abort_on_failure);
+            let mut ctx = BenchmarkContext::new(workload.clone(), Component::Baseline, false).await;
+            let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+            path.extend(["move_package"]);
+            let move_package = ctx
+                .publish_package(PublishData::Source(path, false))
+                .await
+                .0;
+
+            // Replace the transaction of one account with one that fails, so that the failure
+            // happens among passing transactions executed in parallel.
+            let (tx_generator, _) = workload.create_tx_generators(&mut ctx).await;
+            let mut transactions = ctx.generate_transactions(tx_generator).await;
+            let account = ctx.user_accounts.values().next().unwrap().clone();
+            transactions.retain(|tx| tx.transaction_data().sender() != account.sender);
+            let failing_transaction = out_of_gas_transaction(&ctx, &account, move_package);
+            let failing_digest = *failing_transaction.digest();
+            transactions.push(failing_transaction);
+            let tx_count = transactions.len();
+            let certificates = ctx.certify_transactions(transactions, true).await;
+
+            let result =
+                AssertUnwindSafe(ctx.benchmark_transaction_execution(certificates, vec![], false))
+                    .catch_unwind()
+                    .await;
+            if abort_on_failure {
+                let panic = result.unwrap_err();
+                let message = panic.downcast_ref::<String>().unwrap();
+                assert!(
+                    message.contains(&failing_digest.to_string()),
+                    "unexpected panic: {message}"
+                );
+            } else {
+                // Failures are tolerated and returned along with the passing transactions.
+                let effects = result.unwrap();
+                assert_eq!(effects.len(), tx_count);
+                let failed: Vec<_> = effects.iter().filter(|e| !e.status().is_ok()).collect();
+                assert_eq!(failed.len(), 1);
+                assert_eq!(*failed[0].transaction_digest(), failing_digest);
+            }
+        }
+    }
+
+    #[sim_test]
+    async fn test_under_budget_transactions_are_reported() {
+        // With a gas budget at the minimum transaction cost, every benchmarked transaction passes
+        // input checks but runs out of gas during execution.
+        let workload = Workload::new(
+            10,
+            WorkloadKind::PTB {
+                num_transfers: 0,
+                use_native_transfer: false,
+                num_dynamic_fields: 0,
+                computation: u8::MAX,
+                num_shared_objects: 0,
+                num_mints: 0,
+                nft_size: 32,
+                use_batch_mint: false,
+                gas_budget: Some(min_gas_budget(DEFAULT_VALIDATOR_GAS_PRICE)),
+            },
+        )
+        .with_abort_on_failure(false);
+        let mut ctx = BenchmarkContext::new(workload.clone(), Component::Baseline, false).await;
+        let transactions = certified_transactions(&mut ctx, &workload).await;
+        let tx_count = transactions.len();
+
+        let effects = ctx
+            .benchmark_transaction_execution(transactions, vec![], false)
+            .await;
+        assert_eq!(effects.len(), tx_count);
+        for e in &effects {
+            assert!(matches!(
+                e.status(),
+                ExecutionStatus::Failure {
+                    error: ExecutionFailureStatus::InsufficientGas,
+                    ..
+                }
+            ));
+        }
+    }
+
+    #[sim_test]
+    async fn test_warmup_transactions_use_separate_objects() {
+        let workload = Workload::new(
+            5,
+            WorkloadKind::PTB {
+                num_transfers: 1,
+                use_native_transfer: false,
+                num_dynamic_fields: 1,
+                computation: 0,
+                num_shared_objects: 2,
+                num_mints: 0,
+                nft_size: 32,
+                use_batch_mint: false,
+                gas_budget: None,
+            },
+        )
+        .with_warmup_tx_count(2);
+        let mut ctx = BenchmarkContext::new(workload.clone(), Component::Baseline, false).await;
+        let (tx_generator, warmup_tx_generator) = workload.create_tx_generators(&mut ctx).await;
+        let transactions = ctx.generate_transactions(tx_generator).await;
+        let warmup_transactions = ctx
+            .generate_warmup_transactions(warmup_tx_generator.unwrap())
+            .await;
+        assert_eq!(transactions.len(), 5);
+        assert_eq!(warmup_transactions.len(), 2);
+
+        // Returns the packages and the other objects read by the transactions.
+        let inputs = |transactions: &[Transaction]| {
+            let (packages, objects): (HashSet<_>, HashSet<_>) = transactions
+                .iter()
+                .flat_map(|tx| tx.transaction_data().input_objects().unwrap())
+                .partition(|kind| matches!(kind, InputObjectKind::MovePackage(_)));
+            let ids = |kinds: HashSet<InputObjectKind>| -> HashSet<ObjectID> {
+                kinds.iter().map(|kind| kind.object_id()).collect()
+            };
+            (ids(packages), ids(objects))
+        };
+        let (packages, objects) = inputs(&transactions);
+        let (warmup_packages, warmup_objects) = inputs(&warmup_transactions);
+        // Warm-up calls the same package, but shares no gas, owned, dynamic field or shared
+        // object with the benchmarked transactions.
+        assert!(!packages.is_empty());
+        assert_eq!(packages, warmup_packages);
+        assert!(objects.is_disjoint(&warmup_objects));
+    }
+
+    #[sim_test]
+    async fn test_object_change_counts() {
+        let workload_kind = |num_transfers, num_mints| WorkloadKind::PTB {
+            num_transfers,
+            use_native_transfer: true,
+            num_dynamic_fields: 0,
+            computation: 0,
+            num_shared_objects: 0,
+            num_mints,
+            nft_size: 32,
+            use_batch_mint: false,
+            gas_budget: None,
+        };
+        let tx_count = 10;
+        for (num_transfers, num_mints) in [(0, 2), (1, 0)] {
+            let workload = Workload::new(tx_count, workload_kind(num_transfers, num_mints));
+            let mut ctx = BenchmarkContext::new(workload.clone(), Component::Baseline, false).await;
+            let transactions = certified_transactions(&mut ctx, &workload).await;
+            let effects = ctx
+                .execute_transactions_in_memory(
+                    ctx.validator.create_in_memory_store(),
+                    transactions,
+                    None,
+                )
+                .await;
+            let counts = ObjectChangeCounts::new(&effects);
+            let tx_count = tx_count as usize;
+            // Every transaction mutates its gas object, plus the objects it transfers.
+            assert_eq!(
+                counts,
+                ObjectChangeCounts {
+                    created: tx_count * num_mints as usize,
+                    mutated: tx_count * (1 + num_transfers as usize),
+                    deleted: 0,
+                    wrapped: 0,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_tps() {
+        assert_eq!(BenchmarkContext::tps(10, Duration::from_secs(2)), 5.0);
+        assert_eq!(BenchmarkContext::tps(0, Duration::from_secs(2)), 0.0);
+        // Nothing elapsed, with or without executed transactions.
+        assert_eq!(BenchmarkContext::tps(0, Duration::ZERO), 0.0);
+        assert_eq!(BenchmarkContext::tps(10, Duration::ZERO), 0.0);
+    }
+}
@@ -1,9 +1,14 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::workload::{max_reference_gas_price, MIN_REFERENCE_GAS_PRICE};
 use clap::{Parser, Subcommand, ValueEnum};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use strum_macros::EnumIter;
+use sui_protocol_config::ProtocolConfig;
+use sui_storage::FileCompression;
+use sui_types::transaction::DEFAULT_VALIDATOR_GAS_PRICE;
 
 #[derive(Parser)]
 #[clap(
@@ -37,6 +42,14 @@ pub struct Command {
         help = "If true, skip signing on the validators, instead, creating certificates directly using validator secrets"
     )]
     pub skip_signing: bool,
+    #[arg(
+        long,
+        default_value_t = DEFAULT_VALIDATOR_GAS_PRICE,
+        value_parser = parse_reference_gas_price,
+        help = "Reference gas price of the validator. All generated transactions use this gas price. \
+            Must be at least 1, and low enough that the default gas budget stays within the protocol's max_tx_gas"
+    )]
+    pub reference_gas_price: u64,
     #[arg(
         long,
         default_value = "baseline",
@@ -44,6 +57,69 @@ pub struct Command {
         help = "Which component to benchmark"
     )]
     pub component: Component,
+    #[arg(
+        long,
+        help = "Maximum wall-clock time in seconds spent executing the benchmarked transactions. \
+            Once it elapses, outstanding transactions are aborted and the results so far are reported. \
+            Not supported by the txn-signing and checkpoint-executor components"
+    )]
+    pub duration_secs: Option<u64>,
+    #[arg(
+        long,
+        default_value_t = false,
+        help = "If true, failed transactions are counted and reported instead of aborting the benchmark. \
+            Use this for workloads where failures are expected"
+    )]
+    pub allow_failed_transactions: bool,
+    #[arg(
+        long,
+        default_value_t = 0,
+        help = "Number of warm-up transactions of the same workload executed before the measured run. \
+            They use their own accounts and objects, and are not counted in the results. \
+            Not supported by the checkpoint-executor component"
+    )]
+    pub warmup_tx_count: u64,
+    #[arg(
+        long,
+        help = "Write the dependency graph of the benchmarked transactions to this file as JSON. \
+            Edges come from the dependencies recorded in each transaction's effects. \
+            Not supported by the txn-signing component"
+    )]
+    pub export_dependency_graph: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "Write the effects of the benchmarked transactions to this file, in the sui-storage blob file format. \
+            The file is written after the run, outside of the measurement. \
+            Not supported by the txn-signing component"
+    )]
+    pub effects_log: Option<PathBuf>,
+    #[arg(
+        long,
+        default_value = "none",
+        value_parser = parse_file_compression,
+        requires = "effects_log",
+        help = "Compression of the effects log, either none or zstd. \
+            Recorded in the file header, so the reader decompresses it transparently"
+    )]
+    pub effects_log_compression: FileCompression,
+    #[arg(
+        long,
+        conflicts_with = "current_thread_runtime",
+        help = "Number of worker threads of the tokio runtime. \
+            Defaults to the number of logical CPU cores"
+    )]
+    pub worker_threads: Option<NonZeroUsize>,
+    #[arg(
+        long,
+        help = "Maximum number of threads in the tokio blocking thread pool. Defaults to 512"
+    )]
+    pub max_blocking_threads: Option<NonZeroUsize>,
+    #[arg(
+        long,
+        default_value_t = false,
+        help = "If true, run the benchmark on a current-thread tokio runtime instead of a multi-thread one"
+    )]
+    pub current_thread_runtime: bool,
     #[clap(subcommand)]
     pub workload: WorkloadKind,
 }
@@ -131,6 +207,13 @@ pub enum WorkloadKind {
             Otherwise, batch via a PTB with multiple commands"
         )]
         use_batch_mint: bool,
+        #[arg(
+            long,
+            value_parser = parse_gas_budget,
+            help = "Gas budget of each benchmarked transaction. \
+            Defaults to the gas price * 5,000,000 gas units"
+        )]
+        gas_budget: Option<u64>,
     },
     Publish {
         #[arg(
@@ -143,9 +226,45 @@ pub enum WorkloadKind {
             will compile the package first before publishing it."
         )]
         manifest_file: PathBuf,
+        #[arg(
+            long,
+            value_parser = parse_gas_budget,
+            help = "Gas budget of each benchmarked publish transaction. \
+            Defaults to the gas price * 5,000,000 gas units"
+        )]
+        gas_budget: Option<u64>,
     },
 }
 
+fn parse_reference_gas_price(s: &str) -> Result<u64, String> {
+    let reference_gas_price: u64 = s.parse().map_err(|e| format!("{e}"))?;
+    let max_reference_gas_price = max_reference_gas_price();
+    if !(MIN_REFERENCE_GAS_PRICE..=max_reference_gas_price).contains(&reference_gas_price) {
+        return Err(format!(
+            "must be between {} and {}",
+            MIN_REFERENCE_GAS_PRICE, max_reference_gas_price
+        ));
+    }
+    Ok(reference_gas_price)
+}
+
+fn parse_gas_budget(s: &str) -> Result<u64, String> {
+    let gas_budget: u64 = s.parse().map_err(|e| format!("{e}"))?;
+    let max_tx_gas = ProtocolConfig::get_for_max_version_UNSAFE().max_tx_gas();
+    if gas_budget > max_tx_gas {
+        return Err(format!("must be at most max_tx_gas ({})", max_tx_gas));
+    }
+    Ok(gas_budget)
+}
+
+fn parse_file_compression(s: &str) -> Result<FileCompression, String> {
+    match s.to_lowercase().as_str() {
+        "none" => Ok(FileCompression::None),
+        "zstd" => Ok(FileCompression::Zstd),
+        _ => Err("must be either none or zstd".to_string()),
+    }
+}
+
 impl WorkloadKind {
     pub(crate) fn gas_object_num_per_account(&self) -> u64 {
         match self {
@@ -154,4 +273,14 @@ impl WorkloadKind {
             WorkloadKind::Publish { .. } => 1,
         }
     }
+
+    /// Gas budget of the benchmarked transactions, if it is set for this workload.
+    /// Setup transactions always use the default budget.
+    pub(crate) fn gas_budget(&self) -> Option<u64> {
+        match self {
+            WorkloadKind::PTB { gas_budget, .. } | WorkloadKind::Publish { gas_budget, .. } => {
+                *gas_budget
+            }
+        }
+    }
 }
@@ -0,0 +1,48 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use sui_types::base_types::TransactionDigest;
+use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
+
+/// The dependency graph of a set of executed transactions, built from the dependencies recorded
+/// in their effects. A transaction depends on every transaction that wrote a version of an
+/// object it read. Only dependencies within the set are kept, so genesis and setup transactions
+/// do not show up in the graph.
+#[derive(Serialize)]
+pub struct DependencyGraph {
+    pub transactions: Vec<TransactionDigest>,
+    /// (transaction, dependency) pairs.
+    pub dependencies: Vec<(TransactionDigest, TransactionDigest)>,
+}
+
+impl DependencyGraph {
+    pub fn new(effects: &[TransactionEffects]) -> Self {
+        let transactions: Vec<_> = effects.iter().map(|e| *e.transaction_digest()).collect();
+        let in_graph: HashSet<_> = transactions.iter().collect();
+        let dependencies = effects
+            .iter()
+            .flat_map(|e| {
+                e.dependencies()
+                    .iter()
+                    .filter(|dependency| in_graph.contains(dependency))
+                    .map(|dependency| (*e.transaction_digest(), *dependency))
+            })
+            .collect();
+        Self {
+            transactions,
+            dependencies,
+        }
+    }
+
+    /// Write the graph to `path` as JSON.
+    pub fn write_json(&self, path: &Path) -> std::io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use sui_storage::blob::{Blob, BlobEncoding};
+use sui_storage::{FileCompression, StorageFormat};
+use sui_types::effects::TransactionEffects;
+
+pub const EFFECTS_LOG_FILE_MAGIC: u32 = 0x0EFFEC75;
+
+/// Write `effects` to `path` in the same blob file format as archives and snapshots: a header
+/// with the magic number, storage format and compression, followed by one length-prefixed,
+/// bcs-encoded blob per transaction. With `FileCompression::Zstd`, everything after the header
+/// is compressed, at the same level that sui-storage uses.
+pub fn write_effects_log(
+    path: &Path,
+    effects: &[TransactionEffects],
+    file_compression: FileCompression,
+) -> Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writer.write_all(&EFFECTS_LOG_FILE_MAGIC.to_be_bytes())?;
+    writer.write_all(&[u8::from(StorageFormat::Blob), u8::from(file_compression)])?;
+    match file_compression {
+        FileCompression::None => {
+            write_blobs(&mut writer, effects)?;
+            writer.flush()?;
+        }
+        FileCompression::Zstd => {
+            let mut encoder = zstd::Encoder::new(writer, 1)?;
+            write_blobs(&mut encoder, effects)?;
+            encoder.finish()?.flush()?;
+        }
+    }
+    Ok(())
+}
+
+fn write_blobs<W: Write>(writer: &mut W, effects: &[TransactionEffects]) -> Result<()> {
+    for effects in effects {
+        Blob::encode(effects, BlobEncoding::Bcs)?.write(writer)?;
+    }
+    Ok(())
+}
+
+/// Iterates over the effects in a log written by `write_effects_log`, decompressing them
+/// according to the file header.
+/// Unlike `BlobIter`, a truncated or corrupted record is returned as an error instead of ending
+/// the iteration.
+pub struct EffectsLogReader {
+    reader: BufReader<Box<dyn Read>>,
+}
+
+impl EffectsLogReader {
+    pub fn open(path: &Path) -> Result<Self> {
+        let (reader, storage_format) =
+            sui_storage::read(EFFECTS_LOG_FILE_MAGIC, File::open(path)?)?;
+        match storage_format {
+            StorageFormat::Blob => Ok(Self {
+                reader: BufReader::new(reader),
+            }),
+        }
+    }
+
+    fn read_effects(&mut self) -> Result<Option<TransactionEffects>> {
+        // The log may only end at a record boundary.
+        if self.reader.fill_buf()?.is_empty() {
+            return Ok(None);
+        }
+        Blob::read(&mut self.reader)?.decode().map(Some)
+    }
+}
+
+impl Iterator for EffectsLogReader {
+    type Item = Result<TransactionEffects>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_effects().transpose()
+    }
+}
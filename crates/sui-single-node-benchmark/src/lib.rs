@@ -4,9 +4,13 @@
 use crate::benchmark_context::BenchmarkContext;
 use crate::command::Component;
 use crate::workload::Workload;
+use sui_types::effects::TransactionEffects;
+use tracing::warn;
 
 pub(crate) mod benchmark_context;
 pub mod command;
+pub mod dependency_graph;
+pub mod effects_log;
 pub(crate) mod mock_account;
 pub(crate) mod mock_consensus;
 pub(crate) mod mock_storage;
@@ -18,38 +22,71 @@ pub mod workload;
 /// The different kinds of workloads and components can be found in command.rs.
 /// \checkpoint_size represents both the size of a consensus commit, and size of a checkpoint
 /// if we are benchmarking the checkpoint.
+/// Returns the effects of the benchmarked transactions, so that callers can make assertions on
+/// the results. The TxnSigning component does not execute anything and returns no effects.
+/// For the checkpoint executor, these are the effects the checkpoints were built from.
+/// If the workload has a duration limit, fewer effects than transactions are returned when
+/// the limit cuts the run short. Warm-up transactions are never included.
 pub async fn run_benchmark(
-    workload: Workload,
+    mut workload: Workload,
     component: Component,
     checkpoint_size: usize,
     print_sample_tx: bool,
     skip_signing: bool,
-) {
+) -> Vec<TransactionEffects> {
+    if workload.duration.is_some()
+        && matches!(
+            component,
+            Component::TxnSigning | Component::CheckpointExecutor
+        )
+    {
+        warn!(
+            "The duration limit only applies to components that execute transactions, ignoring it"
+        );
+    }
+    if workload.warmup_tx_count > 0 && matches!(component, Component::CheckpointExecutor) {
+        warn!("The checkpoint executor does not support warm-up transactions, ignoring them");
+        workload.warmup_tx_count = 0;
+    }
     let mut ctx = BenchmarkContext::new(workload.clone(), component, print_sample_tx).await;
-    let tx_generator = workload.create_tx_generator(&mut ctx).await;
+    let (tx_generator, warmup_tx_generator) = workload.create_tx_generators(&mut ctx).await;
     let transactions = ctx.generate_transactions(tx_generator).await;
+    let warmup_transactions = match warmup_tx_generator {
+        Some(warmup_tx_generator) => ctx.generate_warmup_transactions(warmup_tx_generator).await,
+        None => vec![],
+    };
     if matches!(component, Component::TxnSigning) {
-        ctx.benchmark_transaction_signing(transactions, print_sample_tx)
+        ctx.benchmark_transaction_signing(transactions, warmup_transactions, print_sample_tx)
             .await;
-        return;
+        return vec![];
     }
 
     let transactions = ctx.certify_transactions(transactions, skip_signing).await;
+    let warmup_transactions = ctx
+        .certify_transactions(warmup_transactions, skip_signing)
+        .await;
     ctx.validator()
         .assigned_shared_object_versions(&transactions)
         .await;
+    ctx.validator()
+        .assigned_shared_object_versions(&warmup_transactions)
+        .await;
     match component {
         Component::CheckpointExecutor => {
             ctx.benchmark_checkpoint_executor(transactions, checkpoint_size)
-                .await;
+                .await
         }
         Component::ExecutionOnly => {
-            ctx.benchmark_transaction_execution_in_memory(transactions, print_sample_tx)
-                .await;
+            ctx.benchmark_transaction_execution_in_memory(
+                transactions,
+                warmup_transactions,
+                print_sample_tx,
+            )
+            .await
         }
         _ => {
-            ctx.benchmark_transaction_execution(transactions, print_sample_tx)
-                .await;
+            ctx.benchmark_transaction_execution(transactions, warmup_transactions, print_sample_tx)
+                .await
         }
     }
 }
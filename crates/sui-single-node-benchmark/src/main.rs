@@ -1,27 +1,111 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
-use sui_single_node_benchmark::command::Command;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use std::time::{Duration, Instant};
+use sui_single_node_benchmark::command::{Command, Component};
+use sui_single_node_benchmark::dependency_graph::DependencyGraph;
+use sui_single_node_benchmark::effects_log::write_effects_log;
 use sui_single_node_benchmark::run_benchmark;
 use sui_single_node_benchmark::workload::Workload;
+use tokio::runtime::Builder;
+use tracing::info;
 
-#[tokio::main]
-async fn main() {
+fn main() {
+    let args = Command::parse();
+
+    let mut builder = if args.current_thread_runtime {
+        Builder::new_current_thread()
+    } else {
+        let mut builder = Builder::new_multi_thread();
+        if let Some(worker_threads) = args.worker_threads {
+            builder.worker_threads(worker_threads.get());
+        }
+        builder
+    };
+    if let Some(max_blocking_threads) = args.max_blocking_threads {
+        builder.max_blocking_threads(max_blocking_threads.get());
+    }
+    let runtime = builder.enable_all().build().unwrap();
+    runtime.block_on(run(args));
+}
+
+async fn run(args: Command) {
     let _guard = telemetry_subscribers::TelemetryConfig::new()
         .with_log_level("off,sui_single_node_benchmark=info")
         .with_env()
         .init();
 
-    let args = Command::parse();
-    run_benchmark(
-        Workload::new(args.tx_count, args.workload),
+    if args.duration_secs.is_some()
+        && matches!(
+            args.component,
+            Component::TxnSigning | Component::CheckpointExecutor
+        )
+    {
+        Command::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--duration-secs is not supported by the txn-signing and checkpoint-executor components",
+            )
+            .exit();
+    }
+    if args.warmup_tx_count > 0 && matches!(args.component, Component::CheckpointExecutor) {
+        Command::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--warmup-tx-count is not supported by the checkpoint-executor component",
+            )
+            .exit();
+    }
+    if args.export_dependency_graph.is_some() && matches!(args.component, Component::TxnSigning) {
+        Command::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--export-dependency-graph is not supported by the txn-signing component",
+            )
+            .exit();
+    }
+    if args.effects_log.is_some() && matches!(args.component, Component::TxnSigning) {
+        Command::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--effects-log is not supported by the txn-signing component",
+            )
+            .exit();
+    }
+    let mut workload = Workload::new(args.tx_count, args.workload)
+        .with_reference_gas_price(args.reference_gas_price)
+        .with_abort_on_failure(!args.allow_failed_transactions)
+        .with_warmup_tx_count(args.warmup_tx_count);
+    if let Some(duration_secs) = args.duration_secs {
+        workload = workload.with_duration(Duration::from_secs(duration_secs));
+    }
+    let effects = run_benchmark(
+        workload,
         args.component,
         args.checkpoint_size,
         args.print_sample_tx,
         args.skip_signing,
     )
     .await;
+    if let Some(path) = args.export_dependency_graph {
+        DependencyGraph::new(&effects).write_json(&path).unwrap();
+        info!("Wrote the transaction dependency graph to {:?}", path);
+    }
+    if let Some(path) = args.effects_log {
+        let start_time = Instant::now();
+        write_effects_log(&path, &effects, args.effects_log_compression).unwrap();
+        let elapsed = start_time.elapsed();
+        info!(
+            "Wrote the effects of {} transactions to {:?} in {:?}, {} bytes with {:?} compression",
+            effects.len(),
+            path,
+            elapsed,
+            std::fs::metadata(&path).unwrap().len(),
+            args.effects_log_compression,
+        );
+    }
 
     if std::env::var("TRACE_FILTER").is_ok() {
         println!("Sleeping for 60 seconds to allow tracing to flush.");
@@ -63,3 +63,48 @@ fn new_gas_object(idx: u64, owner: SuiAddress) -> Object {
     let object_id = ObjectID::from_bytes(id_bytes).unwrap();
     Object::with_id_owner_for_testing(object_id, owner)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use sui_macros::sim_test;
+    use sui_types::{
+        SUI_AUTHENTICATOR_STATE_OBJECT_ID, SUI_BRIDGE_OBJECT_ID, SUI_CLOCK_OBJECT_ID,
+        SUI_DENY_LIST_OBJECT_ID, SUI_RANDOMNESS_STATE_OBJECT_ID, SUI_SYSTEM_STATE_OBJECT_ID,
+        SYSTEM_PACKAGE_ADDRESSES,
+    };
+
+    #[sim_test]
+    async fn test_gas_object_ids_are_deterministic() {
+        let gas_object_ids = |objects: Vec<Object>| -> BTreeSet<ObjectID> {
+            objects.iter().map(|o| o.id()).collect()
+        };
+        let (_, first_objects) = batch_create_account_and_gas(3, 2).await;
+        let (_, second_objects) = batch_create_account_and_gas(3, 2).await;
+        let ids = gas_object_ids(first_objects);
+        assert_eq!(ids.len(), 6);
+        assert_eq!(ids, gas_object_ids(second_objects));
+
+        // Built-in packages and system objects live at small addresses, which all start with a
+        // zero byte, so they never collide with the benchmark's gas objects.
+        let system_ids: Vec<ObjectID> = SYSTEM_PACKAGE_ADDRESSES
+            .iter()
+            .map(|addr| ObjectID::from(*addr))
+            .chain([
+                SUI_SYSTEM_STATE_OBJECT_ID,
+                SUI_CLOCK_OBJECT_ID,
+                SUI_AUTHENTICATOR_STATE_OBJECT_ID,
+                SUI_RANDOMNESS_STATE_OBJECT_ID,
+                SUI_BRIDGE_OBJECT_ID,
+                SUI_DENY_LIST_OBJECT_ID,
+            ])
+            .collect();
+        for id in &system_ids {
+            assert_eq!(id.into_bytes()[0], 0);
+        }
+        for id in &ids {
+            assert_eq!(id.into_bytes()[0], 255);
+        }
+    }
+}
@@ -7,7 +7,8 @@ use move_core_types::language_storage::ModuleId;
 use once_cell::unsync::OnceCell;
 use prometheus::core::{Atomic, AtomicU64};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::Instant;
 use sui_storage::package_object_cache::PackageObjectCache;
 use sui_types::base_types::{EpochId, ObjectID, ObjectRef, SequenceNumber, VersionNumber};
 use sui_types::error::{SuiError, SuiResult};
@@ -24,6 +25,9 @@ pub(crate) struct InMemoryObjectStore {
     objects: Arc<RwLock<HashMap<ObjectID, Object>>>,
     package_cache: Arc<PackageObjectCache>,
     num_object_reads: Arc<AtomicU64>,
+    // Total time spent waiting to acquire the lock on `objects`, in nanoseconds.
+    read_lock_wait_ns: Arc<AtomicU64>,
+    write_lock_wait_ns: Arc<AtomicU64>,
 }
 
 impl InMemoryObjectStore {
@@ -32,6 +36,8 @@ impl InMemoryObjectStore {
             objects: Arc::new(RwLock::new(objects)),
             package_cache: PackageObjectCache::new(),
             num_object_reads: Arc::new(AtomicU64::new(0)),
+            read_lock_wait_ns: Arc::new(AtomicU64::new(0)),
+            write_lock_wait_ns: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -39,6 +45,36 @@ impl InMemoryObjectStore {
         self.num_object_reads.get()
     }
 
+    pub(crate) fn get_read_lock_wait_ns(&self) -> u64 {
+        self.read_lock_wait_ns.get()
+    }
+
+    pub(crate) fn get_write_lock_wait_ns(&self) -> u64 {
+        self.write_lock_wait_ns.get()
+    }
+
+    pub(crate) fn reset_stats(&self) {
+        self.num_object_reads.set(0);
+        self.read_lock_wait_ns.set(0);
+        self.write_lock_wait_ns.set(0);
+    }
+
+    fn read_objects(&self) -> RwLockReadGuard<'_, HashMap<ObjectID, Object>> {
+        let start = Instant::now();
+        let objects = self.objects.read().unwrap();
+        self.read_lock_wait_ns
+            .inc_by(start.elapsed().as_nanos() as u64);
+        objects
+    }
+
+    fn write_objects(&self) -> RwLockWriteGuard<'_, HashMap<ObjectID, Object>> {
+        let start = Instant::now();
+        let objects = self.objects.write().unwrap();
+        self.write_lock_wait_ns
+            .inc_by(start.elapsed().as_nanos() as u64);
+        objects
+    }
+
     // TODO: This function is out-of-sync with read_objects_for_execution from transaction_input_loader.rs.
     // For instance, it does not support the use of deleted shared objects.
     // We will need a trait to unify the these functions. (similarly the one in simulacrum)
@@ -81,7 +117,7 @@ impl InMemoryObjectStore {
     }
 
     pub(crate) fn commit_objects(&self, inner_temp_store: InnerTemporaryStore) {
-        let mut objects = self.objects.write().unwrap();
+        let mut objects = self.write_objects();
         for (object_id, _) in inner_temp_store.mutable_inputs {
             if !inner_temp_store.written.contains_key(&object_id) {
                 objects.remove(&object_id);
@@ -99,7 +135,7 @@ impl ObjectStore for InMemoryObjectStore {
         object_id: &ObjectID,
     ) -> Result<Option<Object>, sui_types::storage::error::Error> {
         self.num_object_reads.inc_by(1);
-        Ok(self.objects.read().unwrap().get(object_id).cloned())
+        Ok(self.read_objects().get(object_id).cloned())
     }
 
     fn get_object_by_key(
@@ -178,3 +214,26 @@ impl GetSharedLocks for InMemoryObjectStore {
         unreachable!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_read_lock_wait_is_recorded() {
+        let store = InMemoryObjectStore::new(HashMap::new());
+        let guard = store.objects.write().unwrap();
+        let reader = {
+            let store = store.clone();
+            std::thread::spawn(move || store.get_object(&ObjectID::ZERO).unwrap())
+        };
+        // Keep the reader blocked on the lock for a while.
+        std::thread::sleep(Duration::from_millis(50));
+        drop(guard);
+        assert!(reader.join().unwrap().is_none());
+
+        assert!(store.get_read_lock_wait_ns() >= Duration::from_millis(10).as_nanos() as u64);
+        assert_eq!(store.get_write_lock_wait_ns(), 0);
+    }
+}
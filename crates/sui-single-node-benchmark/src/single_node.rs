@@ -28,11 +28,14 @@ use sui_types::messages_grpc::HandleTransactionResponse;
 use sui_types::mock_checkpoint_builder::{MockCheckpointBuilder, ValidatorKeypairProvider};
 use sui_types::object::Object;
 use sui_types::transaction::{
-    CertifiedTransaction, Transaction, TransactionDataAPI, VerifiedCertificate,
-    VerifiedTransaction, DEFAULT_VALIDATOR_GAS_PRICE,
+    CertifiedTransaction, Transaction, TransactionDataAPI, VerifiedCertificate, VerifiedTransaction,
 };
 use tokio::sync::broadcast;
 
+/// A handle to the validator under benchmark. Cloning it is cheap and all clones share the same
+/// `AuthorityState` and epoch store, so the parallel execution paths clone it into every
+/// spawned task. Concurrent transactions are kept apart by the authority's own object locking
+/// and versioning, not by the handle.
 #[derive(Clone)]
 pub struct SingleValidator {
     validator_service: Arc<ValidatorService>,
@@ -40,10 +43,15 @@ pub struct SingleValidator {
 }
 
 impl SingleValidator {
-    pub(crate) async fn new(genesis_objects: &[Object], component: Component) -> Self {
+    pub(crate) async fn new(
+        genesis_objects: &[Object],
+        component: Component,
+        reference_gas_price: u64,
+    ) -> Self {
         let validator = TestAuthorityBuilder::new()
             .disable_indexer()
             .with_starting_objects(genesis_objects)
+            .with_reference_gas_price(reference_gas_price)
             // This is needed to properly run checkpoint executor.
             .insert_genesis_checkpoint()
             .build()
@@ -97,8 +105,9 @@ impl SingleValidator {
         keypair: &AccountKeyPair,
         gas: ObjectRef,
     ) -> (ObjectRef, ObjectRef) {
-        let tx_builder = TestTransactionBuilder::new(sender, gas, DEFAULT_VALIDATOR_GAS_PRICE)
-            .publish_with_data(publish_data);
+        let tx_builder =
+            TestTransactionBuilder::new(sender, gas, self.epoch_store.reference_gas_price())
+                .publish_with_data(publish_data);
         let transaction = tx_builder.build_and_sign(keypair);
         let effects = self.execute_raw_transaction(transaction).await;
         let package = effects
@@ -184,7 +193,6 @@ impl SingleValidator {
                 unreachable!()
             }
         };
-        assert!(effects.status().is_ok());
         effects
     }
 
@@ -225,7 +233,6 @@ impl SingleValidator {
                 signer,
                 *executable.digest(),
             );
-        assert!(effects.status().is_ok());
         store.commit_objects(inner_temp_store);
         effects
     }
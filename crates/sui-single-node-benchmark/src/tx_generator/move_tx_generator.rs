@@ -8,10 +8,12 @@ use std::collections::HashMap;
 use sui_test_transaction_builder::TestTransactionBuilder;
 use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber, SuiAddress};
 use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
-use sui_types::transaction::{CallArg, ObjectArg, Transaction, DEFAULT_VALIDATOR_GAS_PRICE};
+use sui_types::transaction::{CallArg, ObjectArg, Transaction};
 
 pub struct MoveTxGenerator {
     move_package: ObjectID,
+    gas_price: u64,
+    gas_budget: Option<u64>,
     num_transfers: u64,
     use_native_transfer: bool,
     computation: u8,
@@ -25,6 +27,8 @@ pub struct MoveTxGenerator {
 impl MoveTxGenerator {
     pub fn new(
         move_package: ObjectID,
+        gas_price: u64,
+        gas_budget: Option<u64>,
         num_transfers: u64,
         use_native_transfer: bool,
         computation: u8,
@@ -36,6 +40,8 @@ impl MoveTxGenerator {
     ) -> Self {
         Self {
             move_package,
+            gas_price,
+            gas_budget,
             num_transfers,
             use_native_transfer,
             computation,
@@ -155,13 +161,13 @@ impl TxGenerator for MoveTxGenerator {
             }
             builder.finish()
         };
-        TestTransactionBuilder::new(
-            account.sender,
-            account.gas_objects[0],
-            DEFAULT_VALIDATOR_GAS_PRICE,
-        )
-        .programmable(pt)
-        .build_and_sign(account.keypair.as_ref())
+        let mut tx_builder =
+            TestTransactionBuilder::new(account.sender, account.gas_objects[0], self.gas_price)
+                .programmable(pt);
+        if let Some(gas_budget) = self.gas_budget {
+            tx_builder = tx_builder.with_gas_budget(gas_budget);
+        }
+        tx_builder.build_and_sign(account.keypair.as_ref())
     }
 
     fn name(&self) -> &'static str {
@@ -12,15 +12,21 @@ use std::path::PathBuf;
 use sui_move_build::{BuildConfig, CompiledPackage};
 use sui_test_transaction_builder::{PublishData, TestTransactionBuilder};
 use sui_types::base_types::ObjectID;
-use sui_types::transaction::{Transaction, DEFAULT_VALIDATOR_GAS_PRICE};
+use sui_types::transaction::Transaction;
 use tracing::info;
 
 pub struct PackagePublishTxGenerator {
     compiled_package: CompiledPackage,
+    gas_price: u64,
+    gas_budget: Option<u64>,
 }
 
 impl PackagePublishTxGenerator {
-    pub async fn new(ctx: &mut BenchmarkContext, manifest_path: PathBuf) -> Self {
+    pub async fn new(
+        ctx: &mut BenchmarkContext,
+        manifest_path: PathBuf,
+        gas_budget: Option<u64>,
+    ) -> Self {
         let manifest = load_manifest_json(&manifest_path);
         let dir = manifest_path.parent().unwrap();
         let PackageDependencyManifest {
@@ -85,19 +91,23 @@ impl PackagePublishTxGenerator {
         let compiled_package = BuildConfig::new_for_testing_replace_addresses(dep_map)
             .build(target_path)
             .unwrap();
-        Self { compiled_package }
+        Self {
+            compiled_package,
+            gas_price: ctx.reference_gas_price(),
+            gas_budget,
+        }
     }
 }
 
 impl TxGenerator for PackagePublishTxGenerator {
     fn generate_tx(&self, account: Account) -> Transaction {
-        TestTransactionBuilder::new(
-            account.sender,
-            account.gas_objects[0],
-            DEFAULT_VALIDATOR_GAS_PRICE,
-        )
-        .publish_with_data(PublishData::CompiledPackage(self.compiled_package.clone()))
-        .build_and_sign(account.keypair.as_ref())
+        let mut tx_builder =
+            TestTransactionBuilder::new(account.sender, account.gas_objects[0], self.gas_price)
+                .publish_with_data(PublishData::CompiledPackage(self.compiled_package.clone()));
+        if let Some(gas_budget) = self.gas_budget {
+            tx_builder = tx_builder.with_gas_budget(gas_budget);
+        }
+        tx_builder.build_and_sign(account.keypair.as_ref())
     }
 
     fn name(&self) -> &'static str {
@@ -5,32 +5,32 @@ use crate::mock_account::Account;
 use crate::tx_generator::TxGenerator;
 use sui_test_transaction_builder::TestTransactionBuilder;
 use sui_types::base_types::ObjectID;
-use sui_types::transaction::{Transaction, DEFAULT_VALIDATOR_GAS_PRICE};
+use sui_types::transaction::Transaction;
 
 pub struct SharedObjectCreateTxGenerator {
     move_package: ObjectID,
+    gas_price: u64,
 }
 
 impl SharedObjectCreateTxGenerator {
-    pub fn new(move_package: ObjectID) -> Self {
-        Self { move_package }
+    pub fn new(move_package: ObjectID, gas_price: u64) -> Self {
+        Self {
+            move_package,
+            gas_price,
+        }
     }
 }
 
 impl TxGenerator for SharedObjectCreateTxGenerator {
     fn generate_tx(&self, account: Account) -> Transaction {
-        TestTransactionBuilder::new(
-            account.sender,
-            account.gas_objects[0],
-            DEFAULT_VALIDATOR_GAS_PRICE,
-        )
-        .move_call(
-            self.move_package,
-            "benchmark",
-            "create_shared_counter",
-            vec![],
-        )
-        .build_and_sign(account.keypair.as_ref())
+        TestTransactionBuilder::new(account.sender, account.gas_objects[0], self.gas_price)
+            .move_call(
+                self.move_package,
+                "benchmark",
+                "create_shared_counter",
+                vec![],
+            )
+            .build_and_sign(account.keypair.as_ref())
     }
 
     fn name(&self) -> &'static str {
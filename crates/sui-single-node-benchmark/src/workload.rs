@@ -6,12 +6,47 @@ use crate::command::WorkloadKind;
 use crate::tx_generator::{MoveTxGenerator, PackagePublishTxGenerator, TxGenerator};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+use sui_protocol_config::ProtocolConfig;
 use sui_test_transaction_builder::PublishData;
+use sui_types::base_types::ObjectID;
+use sui_types::gas_model::gas_predicates::txn_base_cost_as_multiplier;
+use sui_types::transaction::{
+    DEFAULT_VALIDATOR_GAS_PRICE, TEST_ONLY_GAS_UNIT_FOR_HEAVY_COMPUTATION_STORAGE,
+};
+
+/// A gas price of 0 makes the gas budget of generated transactions 0, which is below the
+/// minimum transaction cost.
+pub const MIN_REFERENCE_GAS_PRICE: u64 = 1;
+
+/// Highest reference gas price the benchmark supports. Generated transactions use
+/// `TestTransactionBuilder`'s default gas budget, which is gas price *
+/// `TEST_ONLY_GAS_UNIT_FOR_HEAVY_COMPUTATION_STORAGE`. This must stay within the protocol's
+/// `max_tx_gas`, and the price itself within `max_gas_price`.
+pub fn max_reference_gas_price() -> u64 {
+    let config = ProtocolConfig::get_for_max_version_UNSAFE();
+    (config.max_tx_gas() / TEST_ONLY_GAS_UNIT_FOR_HEAVY_COMPUTATION_STORAGE)
+        .min(config.max_gas_price())
+}
+
+/// Lowest gas budget the validator accepts for a transaction at `reference_gas_price`.
+pub(crate) fn min_gas_budget(reference_gas_price: u64) -> u64 {
+    let config = ProtocolConfig::get_for_max_version_UNSAFE();
+    if txn_base_cost_as_multiplier(&config) {
+        config.base_tx_cost_fixed() * reference_gas_price
+    } else {
+        config.base_tx_cost_fixed()
+    }
+}
 
 #[derive(Clone)]
 pub struct Workload {
     pub tx_count: u64,
     pub workload_kind: WorkloadKind,
+    pub reference_gas_price: u64,
+    pub duration: Option<Duration>,
+    pub abort_on_failure: bool,
+    pub warmup_tx_count: u64,
 }
 
 impl Workload {
@@ -19,9 +54,52 @@ impl Workload {
         Self {
             tx_count,
             workload_kind,
+            reference_gas_price: DEFAULT_VALIDATOR_GAS_PRICE,
+            duration: None,
+            abort_on_failure: true,
+            warmup_tx_count: 0,
         }
     }
 
+    /// Set the reference gas price of the validator. Every generated transaction
+    /// uses this price, so they always match the price the validator expects.
+    /// Panics if the price is outside of [MIN_REFERENCE_GAS_PRICE, max_reference_gas_price()].
+    pub fn with_reference_gas_price(mut self, reference_gas_price: u64) -> Self {
+        let max_reference_gas_price = max_reference_gas_price();
+        assert!(
+            (MIN_REFERENCE_GAS_PRICE..=max_reference_gas_price).contains(&reference_gas_price),
+            "Reference gas price must be between {} and {}, got {}",
+            MIN_REFERENCE_GAS_PRICE,
+            max_reference_gas_price,
+            reference_gas_price
+        );
+        self.reference_gas_price = reference_gas_price;
+        self
+    }
+
+    /// Stop executing the benchmarked transactions once `duration` has elapsed, instead of
+    /// waiting for all of them to finish.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// By default the benchmark aborts at the first benchmarked transaction that fails to execute.
+    /// When disabled, failed transactions are counted and reported at the end of the run instead.
+    pub fn with_abort_on_failure(mut self, abort_on_failure: bool) -> Self {
+        self.abort_on_failure = abort_on_failure;
+        self
+    }
+
+    /// Execute `warmup_tx_count` transactions of the same workload before the measured run, to
+    /// warm up caches and lazily initialized state. They are not counted in the results, and
+    /// use their own accounts and objects, so they do not change any object that the
+    /// benchmarked transactions read.
+    pub fn with_warmup_tx_count(mut self, warmup_tx_count: u64) -> Self {
+        self.warmup_tx_count = warmup_tx_count;
+        self
+    }
+
     pub(crate) fn num_accounts(&self) -> u64 {
         self.tx_count
     }
@@ -30,45 +108,109 @@ impl Workload {
         self.workload_kind.gas_object_num_per_account()
     }
 
-    pub(crate) async fn create_tx_generator(
+    /// Create the generator of the benchmarked transactions, and the generator of the warm-up
+    /// transactions if the workload has any. The warm-up generator is created for the warm-up
+    /// accounts of `ctx`.
+    pub(crate) async fn create_tx_generators(
         &self,
         ctx: &mut BenchmarkContext,
-    ) -> Arc<dyn TxGenerator> {
+    ) -> (Arc<dyn TxGenerator>, Option<Arc<dyn TxGenerator>>) {
+        let gas_budget = self.workload_kind.gas_budget();
+        if let Some(gas_budget) = gas_budget {
+            let min_gas_budget = min_gas_budget(ctx.reference_gas_price());
+            assert!(
+                gas_budget >= min_gas_budget,
+                "Gas budget {} is below the minimum transaction cost {} at reference gas price {}",
+                gas_budget,
+                min_gas_budget,
+                ctx.reference_gas_price()
+            );
+        }
         match &self.workload_kind {
             WorkloadKind::PTB {
-                num_transfers,
-                use_native_transfer,
-                num_dynamic_fields,
-                computation,
-                num_shared_objects,
-                num_mints,
-                nft_size,
-                use_batch_mint,
+                num_shared_objects, ..
             } => {
                 let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
                 path.extend(["move_package"]);
                 let move_package = ctx.publish_package(PublishData::Source(path, false)).await;
-                let root_objects = ctx
-                    .preparing_dynamic_fields(move_package.0, *num_dynamic_fields)
-                    .await;
-                let shared_objects = ctx
-                    .prepare_shared_objects(move_package.0, *num_shared_objects)
+                let tx_generator = self
+                    .create_move_tx_generator(ctx, move_package.0, gas_budget)
                     .await;
-                Arc::new(MoveTxGenerator::new(
-                    move_package.0,
-                    *num_transfers,
-                    *use_native_transfer,
-                    *computation,
-                    root_objects,
-                    shared_objects,
-                    *num_mints,
-                    *nft_size,
-                    *use_batch_mint,
-                ))
+                let warmup_tx_generator = if self.warmup_tx_count > 0 {
+                    assert!(
+                        self.warmup_tx_count >= *num_shared_objects as u64,
+                        "Warm-up needs at least one transaction per shared object, got {} for {} shared objects",
+                        self.warmup_tx_count,
+                        num_shared_objects
+                    );
+                    // Warm-up transactions call the same package, so that its modules are
+                    // cached, but on their own dynamic fields and shared objects.
+                    ctx.swap_warmup_accounts();
+                    let warmup_tx_generator = self
+                        .create_move_tx_generator(ctx, move_package.0, gas_budget)
+                        .await;
+                    ctx.swap_warmup_accounts();
+                    Some(warmup_tx_generator)
+                } else {
+                    None
+                };
+                (tx_generator, warmup_tx_generator)
             }
             WorkloadKind::Publish {
                 manifest_file: manifest_path,
-            } => Arc::new(PackagePublishTxGenerator::new(ctx, manifest_path.clone()).await),
+                gas_budget: _,
+            } => {
+                let tx_generator: Arc<dyn TxGenerator> = Arc::new(
+                    PackagePublishTxGenerator::new(ctx, manifest_path.clone(), gas_budget).await,
+                );
+                // Publish transactions only read the immutable dependency packages, so warm-up
+                // can use the same generator.
+                let warmup_tx_generator = (self.warmup_tx_count > 0).then(|| tx_generator.clone());
+                (tx_generator, warmup_tx_generator)
+            }
         }
     }
+
+    /// Prepare the objects of a PTB workload for the user accounts of `ctx`, and create a
+    /// generator of transactions calling `move_package` on them.
+    async fn create_move_tx_generator(
+        &self,
+        ctx: &mut BenchmarkContext,
+        move_package: ObjectID,
+        gas_budget: Option<u64>,
+    ) -> Arc<dyn TxGenerator> {
+        let WorkloadKind::PTB {
+            num_transfers,
+            use_native_transfer,
+            num_dynamic_fields,
+            computation,
+            num_shared_objects,
+            num_mints,
+            nft_size,
+            use_batch_mint,
+            gas_budget: _,
+        } = &self.workload_kind
+        else {
+            unreachable!("Only PTB workloads call Move");
+        };
+        let root_objects = ctx
+            .preparing_dynamic_fields(move_package, *num_dynamic_fields)
+            .await;
+        let shared_objects = ctx
+            .prepare_shared_objects(move_package, *num_shared_objects)
+            .await;
+        Arc::new(MoveTxGenerator::new(
+            move_package,
+            ctx.reference_gas_price(),
+            gas_budget,
+            *num_transfers,
+            *use_native_transfer,
+            *computation,
+            root_objects,
+            shared_objects,
+            *num_mints,
+            *nft_size,
+            *use_batch_mint,
+        ))
+    }
 }
@@ -1,12 +1,20 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashSet;
 use std::path::PathBuf;
+use std::time::Duration;
 use strum::IntoEnumIterator;
 use sui_macros::sim_test;
 use sui_single_node_benchmark::command::{Component, WorkloadKind};
+use sui_single_node_benchmark::dependency_graph::DependencyGraph;
+use sui_single_node_benchmark::effects_log::{write_effects_log, EffectsLogReader};
 use sui_single_node_benchmark::run_benchmark;
-use sui_single_node_benchmark::workload::Workload;
+use sui_single_node_benchmark::workload::{
+    max_reference_gas_price, Workload, MIN_REFERENCE_GAS_PRICE,
+};
+use sui_storage::FileCompression;
+use sui_types::effects::TransactionEffectsAPI;
 
 #[sim_test]
 async fn benchmark_non_move_transactions_smoke_test() {
@@ -24,6 +32,7 @@ async fn benchmark_non_move_transactions_smoke_test() {
                         num_mints: 0,
                         nft_size: 528,
                         use_batch_mint: false,
+                        gas_budget: None,
                     },
                 ),
                 component,
@@ -52,6 +61,7 @@ async fn benchmark_move_transactions_smoke_test() {
                         num_mints: 2,
                         nft_size: 528,
                         use_batch_mint: false,
+                        gas_budget: None,
                     },
                 ),
                 component,
@@ -80,6 +90,7 @@ async fn benchmark_batch_mint_smoke_test() {
                         num_mints: 10,
                         nft_size: 256,
                         use_batch_mint: true,
+                        gas_budget: None,
                     },
                 ),
                 component,
@@ -92,6 +103,333 @@ async fn benchmark_batch_mint_smoke_test() {
     }
 }
 
+#[sim_test]
+async fn benchmark_reference_gas_price_bounds_smoke_test() {
+    for reference_gas_price in [MIN_REFERENCE_GAS_PRICE, max_reference_gas_price()] {
+        for component in Component::iter() {
+            run_benchmark(
+                Workload::new(
+                    10,
+                    WorkloadKind::PTB {
+                        num_transfers: 1,
+                        use_native_transfer: false,
+                        num_dynamic_fields: 1,
+                        computation: 0,
+                        num_shared_objects: 1,
+                        num_mints: 0,
+                        nft_size: 32,
+                        use_batch_mint: false,
+                        gas_budget: None,
+                    },
+                )
+                .with_reference_gas_price(reference_gas_price),
+                component,
+                1000,
+                false,
+                false,
+            )
+            .await;
+        }
+    }
+}
+
+#[sim_test]
+async fn benchmark_returns_effects_smoke_test() {
+    for component in Component::iter() {
+        let effects = run_benchmark(
+            Workload::new(
+                10,
+                WorkloadKind::PTB {
+                    num_transfers: 1,
+                    use_native_transfer: false,
+                    num_dynamic_fields: 0,
+                    computation: 0,
+                    num_shared_objects: 1,
+                    num_mints: 1,
+                    nft_size: 32,
+                    use_batch_mint: false,
+                    gas_budget: None,
+                },
+            ),
+            component,
+            1000,
+            false,
+            true,
+        )
+        .await;
+        if matches!(component, Component::TxnSigning) {
+            assert!(effects.is_empty());
+        } else {
+            assert_eq!(effects.len(), 10);
+            assert!(effects.iter().all(|e| e.status().is_ok()));
+        }
+    }
+}
+
+#[sim_test]
+async fn benchmark_concurrent_execution_isolation_test() {
+    // Execute a few thousand independent transfers in parallel, and make sure that every
+    // transaction only touched its own objects.
+    let tx_count = 2000;
+    for component in [Component::Baseline, Component::ExecutionOnly] {
+        let effects = run_benchmark(
+            Workload::new(
+                tx_count,
+                WorkloadKind::PTB {
+                    num_transfers: 1,
+                    use_native_transfer: true,
+                    num_dynamic_fields: 0,
+                    computation: 0,
+                    num_shared_objects: 0,
+                    num_mints: 0,
+                    nft_size: 32,
+                    use_batch_mint: false,
+                    gas_budget: None,
+                },
+            ),
+            component,
+            1000,
+            false,
+            true,
+        )
+        .await;
+        assert_eq!(effects.len() as u64, tx_count);
+        let mut mutated = HashSet::new();
+        for e in &effects {
+            assert!(e.status().is_ok());
+            // The gas object and the transferred object.
+            assert_eq!(e.mutated().len(), 2);
+            for (oref, _) in e.mutated() {
+                assert!(mutated.insert(oref.0), "{:?} mutated twice", oref.0);
+            }
+        }
+    }
+}
+
+#[sim_test]
+async fn benchmark_duration_limit_smoke_test() {
+    // With a zero duration the execution phase stops right away. This makes sure that every
+    // component still terminates cleanly and returns the results of the partial run.
+    for num_shared_objects in [0, 1] {
+        for component in Component::iter() {
+            let effects = run_benchmark(
+                Workload::new(
+                    10,
+                    WorkloadKind::PTB {
+                        num_transfers: 1,
+                        use_native_transfer: true,
+                        num_dynamic_fields: 0,
+                        computation: 0,
+                        num_shared_objects,
+                        num_mints: 0,
+                        nft_size: 32,
+                        use_batch_mint: false,
+                        gas_budget: None,
+                    },
+                )
+                .with_duration(Duration::ZERO),
+                component,
+                1000,
+                false,
+                false,
+            )
+            .await;
+            match component {
+                Component::TxnSigning => assert!(effects.is_empty()),
+                // The checkpoint executor ignores the duration limit.
+                Component::CheckpointExecutor => assert_eq!(effects.len(), 10),
+                _ => assert!(effects.len() < 10),
+            }
+        }
+    }
+}
+
+#[sim_test]
+async fn benchmark_warmup_smoke_test() {
+    // Warm-up transactions are executed before the measured run, but never returned as
+    // benchmark results.
+    for num_shared_objects in [0, 2] {
+        for component in Component::iter() {
+            let effects = run_benchmark(
+                Workload::new(
+                    10,
+                    WorkloadKind::PTB {
+                        num_transfers: 1,
+                        use_native_transfer: false,
+                        num_dynamic_fields: 1,
+                        computation: 0,
+                        num_shared_objects,
+                        num_mints: 0,
+                        nft_size: 32,
+                        use_batch_mint: false,
+                        gas_budget: None,
+                    },
+                )
+                .with_warmup_tx_count(3),
+                component,
+                1000,
+                false,
+                false,
+            )
+            .await;
+            match component {
+                Component::TxnSigning => assert!(effects.is_empty()),
+                _ => {
+                    assert_eq!(effects.len(), 10);
+                    assert!(effects.iter().all(|e| e.status().is_ok()));
+                }
+            }
+        }
+    }
+}
+
+#[sim_test]
+async fn benchmark_dependency_graph_smoke_test() {
+    let tx_count = 10;
+    for num_shared_objects in [0, 1] {
+        let effects = run_benchmark(
+            Workload::new(
+                tx_count,
+                WorkloadKind::PTB {
+                    num_transfers: 1,
+                    use_native_transfer: true,
+                    num_dynamic_fields: 0,
+                    computation: 0,
+                    num_shared_objects,
+                    num_mints: 0,
+                    nft_size: 32,
+                    use_batch_mint: false,
+                    gas_budget: None,
+                },
+            ),
+            Component::Baseline,
+            1000,
+            false,
+            false,
+        )
+        .await;
+        let graph = DependencyGraph::new(&effects);
+        assert_eq!(graph.transactions.len(), tx_count as usize);
+        if num_shared_objects == 0 {
+            // Transactions only touch objects of their own account, created at genesis.
+            assert!(graph.dependencies.is_empty());
+        } else {
+            // Every transaction increments the same counter, which chains them in execution
+            // order: all but the first depend on exactly one other, and each is depended on
+            // at most once.
+            assert_eq!(graph.dependencies.len(), tx_count as usize - 1);
+            let dependents: HashSet<_> = graph.dependencies.iter().map(|(tx, _)| tx).collect();
+            let dependencies: HashSet<_> = graph.dependencies.iter().map(|(_, dep)| dep).collect();
+            assert_eq!(dependents.len(), graph.dependencies.len());
+            assert_eq!(dependencies.len(), graph.dependencies.len());
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "dependency_graph_{}_shared_objects.json",
+            num_shared_objects
+        ));
+        graph.write_json(&path).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(
+            json["dependencies"].as_array().unwrap().len(),
+            graph.dependencies.len()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
+
+#[sim_test]
+async fn benchmark_effects_log_smoke_test() {
+    for component in [Component::Baseline, Component::CheckpointExecutor] {
+        let effects = run_benchmark(
+            Workload::new(
+                10,
+                WorkloadKind::PTB {
+                    num_transfers: 1,
+                    use_native_transfer: false,
+                    num_dynamic_fields: 1,
+                    computation: 0,
+                    num_shared_objects: 1,
+                    num_mints: 1,
+                    nft_size: 32,
+                    use_batch_mint: false,
+                    gas_budget: None,
+                },
+            ),
+            component,
+            1000,
+            false,
+            false,
+        )
+        .await;
+        assert_eq!(effects.len(), 10);
+
+        let path = std::env::temp_dir().join("effects_log_smoke_test.bcs");
+        write_effects_log(&path, &effects, FileCompression::None).unwrap();
+        let logged = EffectsLogReader::open(&path)
+            .unwrap()
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(logged, effects);
+
+        // A truncated log fails to decode instead of silently dropping the last record.
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+        let mut reader = EffectsLogReader::open(&path).unwrap();
+        for _ in 0..effects.len() - 1 {
+            reader.next().unwrap().unwrap();
+        }
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+        std::fs::remove_file(path).unwrap();
+    }
+}
+
+#[sim_test]
+async fn benchmark_effects_log_compression_smoke_test() {
+    // Every transaction mints and transfers several objects, so that effects are large and
+    // mostly made of object references.
+    let effects = run_benchmark(
+        Workload::new(
+            100,
+            WorkloadKind::PTB {
+                num_transfers: 2,
+                use_native_transfer: false,
+                num_dynamic_fields: 0,
+                computation: 0,
+                num_shared_objects: 0,
+                num_mints: 10,
+                nft_size: 32,
+                use_batch_mint: false,
+                gas_budget: None,
+            },
+        ),
+        Component::Baseline,
+        1000,
+        false,
+        false,
+    )
+    .await;
+    let mut sizes = vec![];
+    for file_compression in [FileCompression::None, FileCompression::Zstd] {
+        let path = std::env::temp_dir().join(format!(
+            "effects_log_compression_smoke_test_{:?}.bcs",
+            file_compression
+        ));
+        write_effects_log(&path, &effects, file_compression).unwrap();
+        // The reader picks the compression up from the file header.
+        let logged = EffectsLogReader::open(&path)
+            .unwrap()
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(logged, effects);
+        sizes.push(std::fs::metadata(&path).unwrap().len());
+        std::fs::remove_file(path).unwrap();
+    }
+    assert!(sizes[1] < sizes[0], "{:?}", sizes);
+}
+
 #[sim_test]
 async fn benchmark_publish_from_source() {
     // This test makes sure that the benchmark runs.
@@ -108,6 +446,7 @@ async fn benchmark_publish_from_source() {
                 10,
                 WorkloadKind::Publish {
                     manifest_file: path.clone(),
+                    gas_budget: None,
                 },
             ),
             component,
@@ -135,6 +474,7 @@ async fn benchmark_publish_from_bytecode() {
                 10,
                 WorkloadKind::Publish {
                     manifest_file: path.clone(),
+                    gas_budget: None,
                 },
             ),
             component,